@@ -41,6 +41,97 @@
 //! let error: ExampleError<dyn Error + 'static> = ExampleError::new().into_dynamic();
 //! ```
 //!
+//! If you want to use the `?` operator to convert a lower-level error
+//! into one of these generated errors, list the source types you want
+//! to convert from after the struct name, separated by the `from`
+//! keyword. This generates a `From` impl for each listed type that
+//! targets the erased `$structname<dyn Error>`, so that `?` will call
+//! `with_source` and erase the source to a common type, letting a
+//! single function propagate any of the listed source types:
+//! ```
+//! use errormake::errormake;
+//! use std::error::Error;
+//!
+//! errormake!(pub WrappedError from std::num::ParseIntError, std::num::ParseFloatError);
+//!
+//! fn parse(input: &str) -> Result<i64, WrappedError<dyn Error + 'static>> {
+//!     if let Some(prefix) = input.strip_prefix('~') {
+//!         Ok(prefix.parse::<f64>()? as i64)
+//!     } else {
+//!         Ok(input.parse::<i64>()?)
+//!     }
+//! }
+//! ```
+//!
+//! If your error type needs several distinct cases instead of a single
+//! flat struct, use the `enum` form. Mark each variant with `(source)`
+//! if it should carry a boxed source error, `{ description }` if it
+//! should carry a description, both, or neither:
+//! ```
+//! use errormake::errormake;
+//!
+//! errormake!(pub enum ConfigError {
+//!     NotFound,
+//!     Parse(source),
+//!     Io { description },
+//! });
+//!
+//! let error = ConfigError::Parse { source: Box::new(ConfigError::NotFound {}) };
+//! ```
+//!
+//! The default `Display` output (`"{name}: {description}"`) can be
+//! overridden with a `#[display("...")]` attribute
+//! on a struct that also declares its own typed fields. The format
+//! string must reference `description` and every declared field by
+//! name (each is passed to the underlying `write!` as a named
+//! argument, so an unused one is a compile error, just as with an
+//! unused `thiserror` field):
+//! ```
+//! use errormake::errormake;
+//!
+//! errormake!(#[display("failed to open {path}: {description}")] pub FileError {
+//!     path: String,
+//! });
+//!
+//! let error = FileError::with_description(
+//!     String::from("permission denied"),
+//!     String::from("/etc/shadow"),
+//! );
+//! assert_eq!(
+//!     "failed to open /etc/shadow: permission denied",
+//!     format!("{}", error),
+//! );
+//! ```
+//!
+//! A struct can also declare typed fields without a custom `#[display]`
+//! attribute, for context a caller needs to react to programmatically
+//! rather than just read. Each field gets a constructor argument and a
+//! public accessor, and the default `Display` behaves as before:
+//! ```
+//! use errormake::errormake;
+//!
+//! errormake!(pub ParseError { line: usize, token: String });
+//!
+//! let error = ParseError::with_description(
+//!     String::from("unexpected token"),
+//!     42,
+//!     String::from("}"),
+//! );
+//! assert_eq!(*error.line(), 42);
+//! assert_eq!(error.token(), "}");
+//! ```
+//!
+//! With a `backtrace` feature enabled, generated structs capture a
+//! [`std::backtrace::Backtrace`] in their constructors, accessible
+//! through a `backtrace()` method (a value made through `Default` holds
+//! none). The macro's output checks `cfg(feature = "backtrace")`, and
+//! that check is evaluated in the crate which invokes `errormake!`: it
+//! is that crate's own `backtrace` feature which turns capture on, and
+//! a `backtrace` feature of errormake itself affects only the structs
+//! defined inside errormake. With the feature disabled (the default),
+//! there is no extra field and no runtime cost. Because `Backtrace`
+//! doesn't implement `Clone`, `Eq`, `Hash`, or `PartialEq`, enabling
+//! the feature drops those derives from generated structs.
 
 #[macro_export]
 /// The macro used to generate basic Error structs.
@@ -49,24 +140,421 @@
 /// documentation.
 macro_rules! errormake {
     ($structname:ident) => {
-        #[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+        #[derive(Debug, Default)]
+        #[cfg_attr(not(feature = "backtrace"), derive(Clone, Eq, Hash, PartialEq))]
         struct $structname<T: ?Sized + 'static> {
             source: Option<Box<T>>,
             description: Option<String>,
+            #[cfg(feature = "backtrace")]
+            backtrace: Option<std::backtrace::Backtrace>,
         }
 
         errormake!(impl $structname);
     };
     ($(#[$meta:meta])* pub $structname:ident) => {
         $(#[$meta])*
-        #[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+        #[derive(Debug, Default)]
+        #[cfg_attr(not(feature = "backtrace"), derive(Clone, Eq, Hash, PartialEq))]
         pub struct $structname<T: ?Sized + 'static> {
             source: Option<Box<T>>,
             description: Option<String>,
+            #[cfg(feature = "backtrace")]
+            backtrace: Option<std::backtrace::Backtrace>,
         }
 
         errormake!(impl $structname);
     };
+    ($structname:ident from $($from:path),+ $(,)?) => {
+        errormake!($structname);
+
+        $(
+            impl From<$from> for $structname<dyn std::error::Error + 'static> {
+                fn from(source: $from) -> Self {
+                    $structname::with_source(source).into_dynamic()
+                }
+            }
+        )+
+    };
+    ($(#[$meta:meta])* pub $structname:ident from $($from:path),+ $(,)?) => {
+        errormake!($(#[$meta])* pub $structname);
+
+        $(
+            impl From<$from> for $structname<dyn std::error::Error + 'static> {
+                fn from(source: $from) -> Self {
+                    $structname::with_source(source).into_dynamic()
+                }
+            }
+        )+
+    };
+    (#[display($fmt:literal)] $(#[$meta:meta])* pub $structname:ident { $($field:ident : $ftype:ty),* $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Debug)]
+        #[cfg_attr(not(feature = "backtrace"), derive(Clone, Eq, Hash, PartialEq))]
+        pub struct $structname<T: ?Sized + 'static> {
+            source: Option<Box<T>>,
+            description: Option<String>,
+            $($field: $ftype,)*
+            #[cfg(feature = "backtrace")]
+            backtrace: Option<std::backtrace::Backtrace>,
+        }
+
+        errormake!(@fielded_impl $structname { $($field: $ftype),* } display($fmt));
+    };
+    (#[display($fmt:literal)] $structname:ident { $($field:ident : $ftype:ty),* $(,)? }) => {
+        #[derive(Debug)]
+        #[cfg_attr(not(feature = "backtrace"), derive(Clone, Eq, Hash, PartialEq))]
+        struct $structname<T: ?Sized + 'static> {
+            source: Option<Box<T>>,
+            description: Option<String>,
+            $($field: $ftype,)*
+            #[cfg(feature = "backtrace")]
+            backtrace: Option<std::backtrace::Backtrace>,
+        }
+
+        errormake!(@fielded_impl $structname { $($field: $ftype),* } display($fmt));
+    };
+    ($(#[$meta:meta])* pub $structname:ident { $($field:ident : $ftype:ty),* $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Debug)]
+        #[cfg_attr(not(feature = "backtrace"), derive(Clone, Eq, Hash, PartialEq))]
+        pub struct $structname<T: ?Sized + 'static> {
+            source: Option<Box<T>>,
+            description: Option<String>,
+            $($field: $ftype,)*
+            #[cfg(feature = "backtrace")]
+            backtrace: Option<std::backtrace::Backtrace>,
+        }
+
+        errormake!(@fielded_impl $structname { $($field: $ftype),* });
+    };
+    ($structname:ident { $($field:ident : $ftype:ty),* $(,)? }) => {
+        #[derive(Debug)]
+        #[cfg_attr(not(feature = "backtrace"), derive(Clone, Eq, Hash, PartialEq))]
+        struct $structname<T: ?Sized + 'static> {
+            source: Option<Box<T>>,
+            description: Option<String>,
+            $($field: $ftype,)*
+            #[cfg(feature = "backtrace")]
+            backtrace: Option<std::backtrace::Backtrace>,
+        }
+
+        errormake!(@fielded_impl $structname { $($field: $ftype),* });
+    };
+    (@fielded_impl $structname:ident { $($field:ident : $ftype:ty),* } display($fmt:literal)) => {
+        #[allow(dead_code)]
+        impl $structname<std::convert::Infallible> {
+            /// Instantiate with the given fields and no description or
+            /// source
+            pub fn new($($field: $ftype),*) -> $structname<std::convert::Infallible> {
+                $structname {
+                    source: None,
+                    description: None,
+                    $($field,)*
+                    #[cfg(feature = "backtrace")]
+                    backtrace: Some(std::backtrace::Backtrace::capture()),
+                }
+            }
+
+            /// Instantiate with the given description and fields, but no
+            /// source
+            pub fn with_description(description: String, $($field: $ftype),*) -> $structname<std::convert::Infallible> {
+                $structname {
+                    source: None,
+                    description: Some(description),
+                    $($field,)*
+                    #[cfg(feature = "backtrace")]
+                    backtrace: Some(std::backtrace::Backtrace::capture()),
+                }
+            }
+        }
+
+        #[allow(dead_code)]
+        impl<T: 'static> $structname<T> {
+            /// Instantiate with the given source and fields, but no
+            /// description
+            pub fn with_source(source: T, $($field: $ftype),*) -> $structname<T> {
+                $structname {
+                    source: Some(Box::new(source)),
+                    description: None,
+                    $($field,)*
+                    #[cfg(feature = "backtrace")]
+                    backtrace: Some(std::backtrace::Backtrace::capture()),
+                }
+            }
+
+            /// Instantiate with the given source, description, and
+            /// fields
+            pub fn with_source_and_description(source: T, description: String, $($field: $ftype),*) -> $structname<T> {
+                $structname {
+                    source: Some(Box::new(source)),
+                    description: Some(description),
+                    $($field,)*
+                    #[cfg(feature = "backtrace")]
+                    backtrace: Some(std::backtrace::Backtrace::capture()),
+                }
+            }
+        }
+
+        #[cfg(feature = "backtrace")]
+        #[allow(dead_code)]
+        impl<T: ?Sized + 'static> $structname<T> {
+            /// Returns the backtrace captured when this error was
+            /// constructed, if the `backtrace` feature is enabled
+            pub fn backtrace(&self) -> Option<&std::backtrace::Backtrace> {
+                self.backtrace.as_ref()
+            }
+        }
+
+        #[allow(dead_code)]
+        impl<T: ?Sized + 'static> $structname<T> {
+            $(
+                /// Returns a reference to this field
+                pub fn $field(&self) -> &$ftype {
+                    &self.$field
+                }
+            )*
+        }
+
+        #[allow(dead_code)]
+        impl<T: std::error::Error + 'static> $structname<T> {
+            /// Convert the source error into a dynamic Error object, if
+            /// it exists
+            pub fn into_dynamic(self) -> $structname<dyn std::error::Error + 'static> {
+                $structname {
+                    source: self.source.map(|source| source as Box<dyn std::error::Error + 'static>),
+                    description: self.description,
+                    $($field: self.$field,)*
+                    #[cfg(feature = "backtrace")]
+                    backtrace: self.backtrace,
+                }
+            }
+        }
+
+        impl<T: ?Sized + 'static> std::fmt::Display for $structname<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let description = match self.description.as_ref() {
+                    Some(description) => description.as_str(),
+                    None => "No description provided",
+                };
+                write!(f, $fmt, description = description, $($field = &self.$field),*)
+            }
+        }
+
+        impl<T> std::error::Error for $structname<T>
+            where T: std::error::Error + 'static
+        {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                self.source
+                    .as_ref()
+                    .map(|err| err.as_ref() as &(dyn std::error::Error + 'static))
+            }
+        }
+
+        impl std::error::Error for $structname<dyn std::error::Error + 'static> {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                self.source
+                    .as_ref()
+                    .map(|err| err.as_ref())
+            }
+        }
+    };
+    (@fielded_impl $structname:ident { $($field:ident : $ftype:ty),* }) => {
+        #[allow(dead_code)]
+        impl $structname<std::convert::Infallible> {
+            /// Instantiate with the given fields and no description or
+            /// source
+            pub fn new($($field: $ftype),*) -> $structname<std::convert::Infallible> {
+                $structname {
+                    source: None,
+                    description: None,
+                    $($field,)*
+                    #[cfg(feature = "backtrace")]
+                    backtrace: Some(std::backtrace::Backtrace::capture()),
+                }
+            }
+
+            /// Instantiate with the given description and fields, but no
+            /// source
+            pub fn with_description(description: String, $($field: $ftype),*) -> $structname<std::convert::Infallible> {
+                $structname {
+                    source: None,
+                    description: Some(description),
+                    $($field,)*
+                    #[cfg(feature = "backtrace")]
+                    backtrace: Some(std::backtrace::Backtrace::capture()),
+                }
+            }
+        }
+
+        #[allow(dead_code)]
+        impl<T: 'static> $structname<T> {
+            /// Instantiate with the given source and fields, but no
+            /// description
+            pub fn with_source(source: T, $($field: $ftype),*) -> $structname<T> {
+                $structname {
+                    source: Some(Box::new(source)),
+                    description: None,
+                    $($field,)*
+                    #[cfg(feature = "backtrace")]
+                    backtrace: Some(std::backtrace::Backtrace::capture()),
+                }
+            }
+
+            /// Instantiate with the given source, description, and
+            /// fields
+            pub fn with_source_and_description(source: T, description: String, $($field: $ftype),*) -> $structname<T> {
+                $structname {
+                    source: Some(Box::new(source)),
+                    description: Some(description),
+                    $($field,)*
+                    #[cfg(feature = "backtrace")]
+                    backtrace: Some(std::backtrace::Backtrace::capture()),
+                }
+            }
+        }
+
+        #[cfg(feature = "backtrace")]
+        #[allow(dead_code)]
+        impl<T: ?Sized + 'static> $structname<T> {
+            /// Returns the backtrace captured when this error was
+            /// constructed, if the `backtrace` feature is enabled
+            pub fn backtrace(&self) -> Option<&std::backtrace::Backtrace> {
+                self.backtrace.as_ref()
+            }
+        }
+
+        #[allow(dead_code)]
+        impl<T: ?Sized + 'static> $structname<T> {
+            $(
+                /// Returns a reference to this field
+                pub fn $field(&self) -> &$ftype {
+                    &self.$field
+                }
+            )*
+        }
+
+        #[allow(dead_code)]
+        impl<T: std::error::Error + 'static> $structname<T> {
+            /// Convert the source error into a dynamic Error object, if
+            /// it exists
+            pub fn into_dynamic(self) -> $structname<dyn std::error::Error + 'static> {
+                $structname {
+                    source: self.source.map(|source| source as Box<dyn std::error::Error + 'static>),
+                    description: self.description,
+                    $($field: self.$field,)*
+                    #[cfg(feature = "backtrace")]
+                    backtrace: self.backtrace,
+                }
+            }
+        }
+
+        impl<T: ?Sized + 'static> std::fmt::Display for $structname<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(
+                    f,
+                    concat!(stringify!($structname), ": {}"),
+                    match self.description.as_ref() {
+                        Some(description) => description,
+                        None => "No description provided",
+                    }
+                )
+            }
+        }
+
+        impl<T> std::error::Error for $structname<T>
+            where T: std::error::Error + 'static
+        {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                self.source
+                    .as_ref()
+                    .map(|err| err.as_ref() as &(dyn std::error::Error + 'static))
+            }
+        }
+
+        impl std::error::Error for $structname<dyn std::error::Error + 'static> {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                self.source
+                    .as_ref()
+                    .map(|err| err.as_ref())
+            }
+        }
+    };
+    (enum $enumname:ident { $($variant:ident $(( $source:ident ))? $({ $description:ident })?),+ $(,)? }) => {
+        enum $enumname {
+            $(
+                $variant {
+                    $($source: Box<dyn std::error::Error + 'static>,)?
+                    $($description: String,)?
+                }
+            ),+
+        }
+
+        errormake!(@enum_impl $enumname { $($variant $(( $source ))? $({ $description })?),+ });
+    };
+    ($(#[$meta:meta])* pub enum $enumname:ident { $($variant:ident $(( $source:ident ))? $({ $description:ident })?),+ $(,)? }) => {
+        $(#[$meta])*
+        pub enum $enumname {
+            $(
+                $variant {
+                    $($source: Box<dyn std::error::Error + 'static>,)?
+                    $($description: String,)?
+                }
+            ),+
+        }
+
+        errormake!(@enum_impl $enumname { $($variant $(( $source ))? $({ $description })?),+ });
+    };
+    (@enum_impl $enumname:ident { $($variant:ident $(( $source:ident ))? $({ $description:ident })?),+ $(,)? }) => {
+        impl std::fmt::Debug for $enumname {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $(
+                        #[allow(unused_variables)]
+                        $enumname::$variant { $($source,)? $($description,)? .. } => {
+                            f.debug_struct(stringify!($variant))
+                                $(.field("source", $source))?
+                                $(.field("description", $description))?
+                                .finish()
+                        }
+                    )+
+                }
+            }
+        }
+
+        impl std::fmt::Display for $enumname {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $(
+                        #[allow(unused_variables)]
+                        $enumname::$variant { $($source,)? $($description,)? .. } => {
+                            write!(
+                                f,
+                                concat!(stringify!($enumname), "::", stringify!($variant), ": {}"),
+                                errormake!(@variant_description $($description)?)
+                            )
+                        }
+                    )+
+                }
+            }
+        }
+
+        impl std::error::Error for $enumname {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                match self {
+                    $(
+                        #[allow(unused_variables)]
+                        $enumname::$variant { $($source,)? .. } => {
+                            errormake!(@variant_source $($source)?)
+                        }
+                    )+
+                }
+            }
+        }
+    };
+    (@variant_description) => { "No description provided" };
+    (@variant_description $description:ident) => { $description.as_str() };
+    (@variant_source) => { None };
+    (@variant_source $source:ident) => { Some($source.as_ref()) };
     (impl $structname:ident) => {
         #[allow(dead_code)]
         impl $structname<std::convert::Infallible> {
@@ -79,6 +567,8 @@ macro_rules! errormake {
                 $structname {
                     source: None,
                     description: None,
+                    #[cfg(feature = "backtrace")]
+                    backtrace: Some(std::backtrace::Backtrace::capture()),
                 }
             }
 
@@ -87,6 +577,8 @@ macro_rules! errormake {
                 $structname {
                     source: None,
                     description: Some(description),
+                    #[cfg(feature = "backtrace")]
+                    backtrace: Some(std::backtrace::Backtrace::capture()),
                 }
             }
         }
@@ -98,6 +590,8 @@ macro_rules! errormake {
                 $structname {
                     source: Some(Box::new(source)),
                     description: None,
+                    #[cfg(feature = "backtrace")]
+                    backtrace: Some(std::backtrace::Backtrace::capture()),
                 }
             }
 
@@ -106,6 +600,8 @@ macro_rules! errormake {
                 $structname {
                     source: Some(Box::new(source)),
                     description: Some(description),
+                    #[cfg(feature = "backtrace")]
+                    backtrace: Some(std::backtrace::Backtrace::capture()),
                 }
             }
         }
@@ -121,10 +617,22 @@ macro_rules! errormake {
                 $structname {
                     source,
                     description,
+                    #[cfg(feature = "backtrace")]
+                    backtrace: Some(std::backtrace::Backtrace::capture()),
                 }
             }
         }
 
+        #[cfg(feature = "backtrace")]
+        #[allow(dead_code)]
+        impl<T: ?Sized + 'static> $structname<T> {
+            /// Returns the backtrace captured when this error was
+            /// constructed, if the `backtrace` feature is enabled
+            pub fn backtrace(&self) -> Option<&std::backtrace::Backtrace> {
+                self.backtrace.as_ref()
+            }
+        }
+
         #[allow(dead_code)]
         impl<T: std::error::Error + 'static> $structname<T> {
             /// Convert the source error into a dynamic Error object, if
@@ -133,20 +641,14 @@ macro_rules! errormake {
                 $structname {
                     source: self.source.map(|source| source as Box<dyn std::error::Error + 'static>),
                     description: self.description,
+                    #[cfg(feature = "backtrace")]
+                    backtrace: self.backtrace,
                 }
             }
         }
 
-        impl<T: std::fmt::Display + ?Sized + 'static> std::fmt::Display for $structname<T> {
+        impl<T: ?Sized + 'static> std::fmt::Display for $structname<T> {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                match &self.source {
-                    Some(source) => write!(
-                        f,
-                        "{}\n\nThe above error caused the following error:\n\n",
-                        source
-                    )?,
-                    None => {}
-                }
                 write!(
                     f,
                     concat!(stringify!($structname), ": {}"),
@@ -154,8 +656,7 @@ macro_rules! errormake {
                         Some(description) => description,
                         None => "No description provided",
                     }
-                )?;
-                Ok(())
+                )
             }
         }
 
@@ -181,9 +682,135 @@ macro_rules! errormake {
 
 errormake!(#[doc="An example of an error struct made by `errormake`"] pub ExampleErrorStruct);
 
+/// Wraps an error to render its full source chain on `Display`.
+///
+/// Generated structs and enums only ever `Display` their own message;
+/// walking `source()` to print the whole chain is left to `Report` so
+/// that rendering stays separate from "what this error is" and can be
+/// reused or restyled without touching the error type itself.
+///
+/// ```
+/// use errormake::{errormake, Report};
+/// use std::error::Error;
+///
+/// errormake!(pub ReportExampleError);
+///
+/// let low_level = ReportExampleError::with_description(String::from("disk full"));
+/// let high_level = ReportExampleError::with_source_and_description(
+///     Box::new(low_level),
+///     String::from("failed to save file"),
+/// );
+/// let boxed: Box<dyn Error> = Box::new(high_level);
+/// assert_eq!(
+///     "error: ReportExampleError: failed to save file\ncaused by: ReportExampleError: disk full\n",
+///     format!("{}", Report::new(&boxed)),
+/// );
+/// ```
+pub struct Report<E>(E);
+
+impl<E> Report<E> {
+    /// Wrap `error` so that its full source chain can be displayed
+    pub fn new(error: E) -> Report<E> {
+        Report(error)
+    }
+}
+
+impl<E: AsRef<dyn std::error::Error + 'static>> Report<E> {
+    /// Returns an iterator over the wrapped error and each of its
+    /// sources in turn, starting with the wrapped error itself and
+    /// ending with the root cause
+    pub fn iter_sources(&self) -> SourceIter<'_> {
+        SourceIter {
+            next: Some(self.0.as_ref()),
+        }
+    }
+}
+
+impl<E: AsRef<dyn std::error::Error + 'static>> std::fmt::Display for Report<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut sources = self.iter_sources();
+        if let Some(top) = sources.next() {
+            writeln!(f, "error: {}", top)?;
+        }
+        for source in sources {
+            writeln!(f, "caused by: {}", source)?;
+        }
+        Ok(())
+    }
+}
+
+/// Iterator over an error's source chain, returned by
+/// [`Report::iter_sources`]
+pub struct SourceIter<'a> {
+    next: Option<&'a (dyn std::error::Error + 'static)>,
+}
+
+impl<'a> Iterator for SourceIter<'a> {
+    type Item = &'a (dyn std::error::Error + 'static);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take()?;
+        self.next = current.source();
+        Some(current)
+    }
+}
+
+errormake!(pub DescribedError);
+
+/// Extension trait adding `.describe()` to any `Result` whose error
+/// type implements [`std::error::Error`], for annotating a fallible
+/// call with context at the call site instead of hand-writing
+/// `with_source_and_description` and a `map_err` every time.
+///
+/// ```
+/// use errormake::DescribeResult;
+/// use std::fs::File;
+///
+/// let result = File::open("/does/not/exist").describe("opening config file");
+/// assert!(result.is_err());
+/// assert_eq!(
+///     "DescribedError: opening config file",
+///     format!("{}", result.unwrap_err())
+/// );
+/// ```
+pub trait DescribeResult<T> {
+    /// Wrap the error variant, if any, with the given description
+    fn describe(
+        self,
+        description: &str,
+    ) -> Result<T, DescribedError<dyn std::error::Error + 'static>>;
+
+    /// Wrap the error variant, if any, with a description computed
+    /// lazily so the caller only pays for formatting it on failure
+    fn describe_with<F: FnOnce() -> String>(
+        self,
+        description: F,
+    ) -> Result<T, DescribedError<dyn std::error::Error + 'static>>;
+}
+
+impl<T, E: std::error::Error + 'static> DescribeResult<T> for Result<T, E> {
+    fn describe(
+        self,
+        description: &str,
+    ) -> Result<T, DescribedError<dyn std::error::Error + 'static>> {
+        self.describe_with(|| String::from(description))
+    }
+
+    fn describe_with<F: FnOnce() -> String>(
+        self,
+        description: F,
+    ) -> Result<T, DescribedError<dyn std::error::Error + 'static>> {
+        self.map_err(|err| {
+            DescribedError::with_source_and_description(err, description()).into_dynamic()
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::errormake;
+    use super::Report;
+    use super::DescribeResult;
     use std::error::Error;
 
     errormake!(TestingError);
@@ -201,7 +828,7 @@ mod tests {
         assert_eq!("TestingError: Custom error message", format!("{}", error2));
         assert!(error2.source().is_none());
         let error3 = TestingError::with_source(Box::new(error2));
-        assert_eq!("TestingError: Custom error message\n\nThe above error caused the following error:\n\nTestingError: No description provided", format!("{}", error3));
+        assert_eq!("TestingError: No description provided", format!("{}", error3));
         assert!(error3.source().is_some());
         let error4 = TestingError::with_source_and_description(
             Box::new(TestingError::with_description(String::from(
@@ -209,11 +836,14 @@ mod tests {
             ))),
             String::from("Another message"),
         );
-        assert_eq!("TestingError: Custom error message\n\nThe above error caused the following error:\n\nTestingError: Another message", format!("{}", error4));
+        assert_eq!("TestingError: Another message", format!("{}", error4));
         assert!(error4.source().is_some());
     }
 
+    // Clone/PartialEq are only derived when `backtrace` is off, since
+    // `Backtrace` implements neither.
     #[test]
+    #[cfg(not(feature = "backtrace"))]
     fn test_derives() {
         let error1 = TestingError::new();
         assert_eq!(error1, error1.clone());
@@ -227,6 +857,158 @@ mod tests {
         assert_ne!(error1, error4);
     }
 
+    errormake!(FromTestingError from std::num::ParseIntError, std::num::ParseFloatError);
+
+    fn parse_either(input: &str) -> Result<i64, FromTestingError<dyn Error + 'static>> {
+        if let Some(prefix) = input.strip_prefix('~') {
+            Ok(prefix.parse::<f64>()? as i64)
+        } else {
+            Ok(input.parse::<i64>()?)
+        }
+    }
+
+    #[test]
+    fn test_from() {
+        let error = parse_either("not a number").unwrap_err();
+        assert!(error.source().is_some());
+        let error = parse_either("~not a float").unwrap_err();
+        assert!(error.source().is_some());
+    }
+
+    #[test]
+    fn test_report() {
+        let low_level = TestingError::with_description(String::from("disk full"));
+        let high_level = TestingError::with_source_and_description(
+            Box::new(low_level),
+            String::from("failed to save file"),
+        );
+        let boxed: Box<dyn Error> = Box::new(high_level);
+        assert_eq!(
+            "error: TestingError: failed to save file\ncaused by: TestingError: disk full\n",
+            format!("{}", Report::new(&boxed))
+        );
+        assert_eq!(2, Report::new(&boxed).iter_sources().count());
+    }
+
+    #[test]
+    fn test_describe() {
+        let result: Result<(), TestingError<std::convert::Infallible>> =
+            Err(TestingError::with_description(String::from("disk full")));
+        let described = result.describe("saving config file");
+        let error = described.unwrap_err();
+        assert_eq!("DescribedError: saving config file", format!("{}", error));
+        assert!(error.source().is_some());
+
+        let result: Result<(), TestingError<std::convert::Infallible>> =
+            Err(TestingError::with_description(String::from("disk full")));
+        let described = result.describe_with(|| format!("saving {}", "config file"));
+        assert_eq!(
+            "DescribedError: saving config file",
+            format!("{}", described.unwrap_err())
+        );
+    }
+
+    errormake!(pub enum EnumTestingError {
+        NotFound,
+        Parse(source),
+        Io { description },
+        Both(source) { description },
+    });
+
+    #[test]
+    fn test_enum() {
+        let not_found = EnumTestingError::NotFound {};
+        assert_eq!(
+            "EnumTestingError::NotFound: No description provided",
+            format!("{}", not_found)
+        );
+        assert!(not_found.source().is_none());
+
+        let parse = EnumTestingError::Parse {
+            source: Box::new(EnumTestingError::NotFound {}),
+        };
+        assert!(parse.source().is_some());
+
+        let io = EnumTestingError::Io {
+            description: String::from("could not read file"),
+        };
+        assert_eq!(
+            "EnumTestingError::Io: could not read file",
+            format!("{}", io)
+        );
+        assert!(io.source().is_none());
+
+        let both = EnumTestingError::Both {
+            source: Box::new(EnumTestingError::NotFound {}),
+            description: String::from("nested failure"),
+        };
+        assert_eq!(
+            "EnumTestingError::Both: nested failure",
+            format!("{}", both)
+        );
+        assert!(both.source().is_some());
+    }
+
+    errormake!(#[display("failed to open {path}: {description}")] TestingFileError {
+        path: String,
+    });
+
+    #[test]
+    fn test_display_format() {
+        let error = TestingFileError::with_description(
+            String::from("permission denied"),
+            String::from("/etc/shadow"),
+        );
+        assert_eq!(
+            "failed to open /etc/shadow: permission denied",
+            format!("{}", error)
+        );
+        assert!(error.source().is_none());
+
+        let wrapped = TestingFileError::with_source(
+            TestingError::with_description(String::from("inner failure")),
+            String::from("/etc/shadow"),
+        );
+        assert!(wrapped.source().is_some());
+    }
+
+    errormake!(pub TestingParseError { line: usize, token: String });
+
+    #[test]
+    fn test_fields() {
+        let error = TestingParseError::with_description(
+            String::from("unexpected token"),
+            42,
+            String::from("}"),
+        );
+        assert_eq!("TestingParseError: unexpected token", format!("{}", error));
+        assert_eq!(42, *error.line());
+        assert_eq!("}", error.token());
+        assert!(error.source().is_none());
+
+        let dynamic = error.into_dynamic();
+        assert_eq!(42, *dynamic.line());
+    }
+
+    // Clone/PartialEq are only derived on fielded structs when
+    // `backtrace` is off, since `Backtrace` implements neither.
+    #[test]
+    #[cfg(not(feature = "backtrace"))]
+    fn test_fields_derives() {
+        let error = TestingParseError::with_description(
+            String::from("unexpected token"),
+            42,
+            String::from("}"),
+        );
+        assert_eq!(error, error.clone());
+        let other = TestingParseError::with_description(
+            String::from("unexpected token"),
+            7,
+            String::from("}"),
+        );
+        assert_ne!(error, other);
+    }
+
     #[test]
     fn test_dynamic() {
         // Test two ways of making the type parameter dynamic